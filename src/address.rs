@@ -0,0 +1,622 @@
+use std::fmt;
+
+use bon::Builder;
+use isocountry::CountryCode;
+
+use crate::{
+    api::{Location, PixelletterErrorCode},
+    Error, Result,
+};
+
+// The house number is written before the street name
+const NUMBER_FIRST: &[CountryCode] = &[
+    CountryCode::AUS,
+    CountryCode::CAN,
+    CountryCode::FRA,
+    CountryCode::GBR,
+    CountryCode::IRL,
+    CountryCode::NZL,
+    CountryCode::USA,
+];
+
+// The region and postcode are written after the city, e.g. "NEW YORK NY 10001"
+const REGION_LAST: &[CountryCode] = &[CountryCode::AUS, CountryCode::CAN, CountryCode::USA];
+
+// The postcode gets a line of its own below the city
+const POSTCODE_LINE: &[CountryCode] = &[CountryCode::GBR, CountryCode::IRL];
+
+// Country lines are written in German, as the letters are posted in Germany or Austria
+const COUNTRY_NAMES: &[(CountryCode, &str)] = &[
+    (CountryCode::AUS, "AUSTRALIEN"),
+    (CountryCode::AUT, "ÖSTERREICH"),
+    (CountryCode::BEL, "BELGIEN"),
+    (CountryCode::BGR, "BULGARIEN"),
+    (CountryCode::CAN, "KANADA"),
+    (CountryCode::CHE, "SCHWEIZ"),
+    (CountryCode::CHN, "CHINA"),
+    (CountryCode::COD, "DEMOKRATISCHE REPUBLIK KONGO"),
+    (CountryCode::COG, "REPUBLIK KONGO"),
+    (CountryCode::CYP, "ZYPERN"),
+    (CountryCode::CZE, "TSCHECHIEN"),
+    (CountryCode::DNK, "DÄNEMARK"),
+    (CountryCode::DEU, "DEUTSCHLAND"),
+    (CountryCode::ESP, "SPANIEN"),
+    (CountryCode::EST, "ESTLAND"),
+    (CountryCode::FIN, "FINNLAND"),
+    (CountryCode::FRA, "FRANKREICH"),
+    (CountryCode::GBR, "GROSSBRITANNIEN"),
+    (CountryCode::GRC, "GRIECHENLAND"),
+    (CountryCode::HRV, "KROATIEN"),
+    (CountryCode::HUN, "UNGARN"),
+    (CountryCode::IRL, "IRLAND"),
+    (CountryCode::ITA, "ITALIEN"),
+    (CountryCode::JPN, "JAPAN"),
+    (CountryCode::KOR, "SÜDKOREA"),
+    (CountryCode::LIE, "LIECHTENSTEIN"),
+    (CountryCode::LTU, "LITAUEN"),
+    (CountryCode::LUX, "LUXEMBURG"),
+    (CountryCode::LVA, "LETTLAND"),
+    (CountryCode::MLT, "MALTA"),
+    (CountryCode::NLD, "NIEDERLANDE"),
+    (CountryCode::NOR, "NORWEGEN"),
+    (CountryCode::NZL, "NEUSEELAND"),
+    (CountryCode::POL, "POLEN"),
+    (CountryCode::PRK, "NORDKOREA"),
+    (CountryCode::PRT, "PORTUGAL"),
+    (CountryCode::ROU, "RUMÄNIEN"),
+    (CountryCode::RUS, "RUSSLAND"),
+    (CountryCode::SVK, "SLOWAKEI"),
+    (CountryCode::SVN, "SLOWENIEN"),
+    (CountryCode::SWE, "SCHWEDEN"),
+    (CountryCode::TUR, "TÜRKEI"),
+    (CountryCode::USA, "USA"),
+    (CountryCode::VGB, "BRITISCHE JUNGFERNINSELN"),
+    (CountryCode::VIR, "AMERIKANISCHE JUNGFERNINSELN"),
+];
+
+// Street prefixes the server rejects with code 205
+const PO_BOX_PREFIXES: &[&str] = &["postfach", "po box", "p.o. box", "p. o. box", "postbox"];
+
+/// A postal address, formatted following the conventions of its country.
+#[derive(Builder, Debug, Clone, PartialEq, Eq)]
+pub struct Address {
+    name: String,
+    company: Option<String>,
+
+    /// Extra line such as a department or "c/o"
+    addition: Option<String>,
+
+    street: String,
+    house_number: Option<String>,
+    postcode: String,
+    city: String,
+
+    /// State, province or territory, needed for the USA, Canada and Australia
+    region: Option<String>,
+
+    country: CountryCode,
+}
+
+impl Address {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn company(&self) -> Option<&str> {
+        self.company.as_deref()
+    }
+
+    pub fn addition(&self) -> Option<&str> {
+        self.addition.as_deref()
+    }
+
+    pub fn street(&self) -> &str {
+        &self.street
+    }
+
+    pub fn house_number(&self) -> Option<&str> {
+        self.house_number.as_deref()
+    }
+
+    pub fn postcode(&self) -> &str {
+        &self.postcode
+    }
+
+    pub fn city(&self) -> &str {
+        &self.city
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    pub fn country(&self) -> CountryCode {
+        self.country
+    }
+
+    /// The address block for a letter posted in Germany, empty lines are left out.
+    pub fn lines(&self) -> Vec<String> {
+        self.lines_from(Location::default().country())
+    }
+
+    /// The address block for a letter posted in `dispatch_country`, which only gets a country line
+    /// if it is sent abroad.
+    pub fn lines_from(&self, dispatch_country: CountryCode) -> Vec<String> {
+        let company = self.company.clone().unwrap_or_default();
+        let addition = self.addition.clone().unwrap_or_default();
+
+        let house_number = self.house_number.as_deref().unwrap_or_default();
+        let street = match NUMBER_FIRST.contains(&self.country) {
+            true => format!("{house_number} {}", self.street),
+            false => format!("{} {house_number}", self.street),
+        };
+
+        let region = self.region.as_deref().unwrap_or_default();
+        let [city, postcode] = if REGION_LAST.contains(&self.country) {
+            [
+                format!("{} {region} {}", self.city, self.postcode),
+                String::new(),
+            ]
+        } else if POSTCODE_LINE.contains(&self.country) {
+            [self.city.clone(), self.postcode.clone()]
+        } else {
+            [format!("{} {}", self.postcode, self.city), String::new()]
+        };
+
+        [
+            company,
+            self.name.clone(),
+            addition,
+            street,
+            city,
+            postcode,
+            self.country_line(dispatch_country),
+        ]
+        .into_iter()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect()
+    }
+
+    // Countries missing from the table fall back to their full ISO name, shortening it could make
+    // two countries indistinguishable
+    fn country_line(&self, dispatch_country: CountryCode) -> String {
+        if self.country == dispatch_country {
+            return String::new();
+        }
+
+        match COUNTRY_NAMES
+            .iter()
+            .find(|(country, _)| *country == self.country)
+        {
+            Some((_, name)) => (*name).to_owned(),
+            None => self.country.name().to_uppercase(),
+        }
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.lines().join("\n"))
+    }
+}
+
+impl From<Address> for String {
+    fn from(address: Address) -> Self {
+        address.to_string()
+    }
+}
+
+/// The recipient of a text order or postcard.
+///
+/// A structured [`Address`] is checked with [`AddressValidator`] before the order is sent,
+/// ready-made lines are passed on unchecked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressBlock {
+    Structured(Box<Address>),
+
+    /// The lines of the address joined with `\n`
+    Lines(String),
+}
+
+impl AddressBlock {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::Structured(address) => AddressValidator.validate(address),
+            Self::Lines(_) => Ok(()),
+        }
+    }
+
+    /// Fails if a structured address lies in another country than the order is addressed to.
+    pub(crate) fn check_destination(&self, destination: CountryCode) -> Result<()> {
+        match self {
+            Self::Structured(address) if address.country != destination => {
+                Err(Error::Validation(format!(
+                    "The destination {} does not match the address country {}!",
+                    destination.alpha2(),
+                    address.country.alpha2()
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn into_lines(self, dispatch_country: CountryCode) -> String {
+        match self {
+            Self::Structured(address) => address.lines_from(dispatch_country).join("\n"),
+            Self::Lines(lines) => lines,
+        }
+    }
+}
+
+impl fmt::Display for AddressBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Structured(address) => address.fmt(f),
+            Self::Lines(lines) => f.write_str(lines),
+        }
+    }
+}
+
+impl From<Address> for AddressBlock {
+    fn from(address: Address) -> Self {
+        Self::Structured(Box::new(address))
+    }
+}
+
+impl From<String> for AddressBlock {
+    fn from(lines: String) -> Self {
+        Self::Lines(lines)
+    }
+}
+
+impl From<&str> for AddressBlock {
+    fn from(lines: &str) -> Self {
+        Self::Lines(lines.to_owned())
+    }
+}
+
+impl From<AddressBlock> for String {
+    fn from(address: AddressBlock) -> Self {
+        match address {
+            AddressBlock::Structured(address) => (*address).into(),
+            AddressBlock::Lines(lines) => lines,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressField {
+    Name,
+    Street,
+    Postcode,
+    City,
+}
+
+/// A problem found in an address, `code` is the error the server would answer with.
+///
+/// Codes 66 to 70 describe some of the same problems, the findings always use codes 203 to 209
+/// which also cover PO boxes and country-specific postcodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddressFinding {
+    pub field: AddressField,
+    pub code: PixelletterErrorCode,
+}
+
+/// Checks addresses locally before they are sent, so obviously broken ones cost no API call.
+///
+/// Text orders and postcards run it on every [`AddressBlock::Structured`] recipient.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AddressValidator;
+
+impl AddressValidator {
+    /// Returns every problem found, at most one per field.
+    pub fn check(&self, address: &Address) -> Vec<AddressFinding> {
+        let finding = |field, code| Some(AddressFinding { field, code });
+
+        let name = match address.name.trim().is_empty() {
+            true => finding(AddressField::Name, PixelletterErrorCode::Code203),
+            false => None,
+        };
+
+        let street = address.street.trim().to_lowercase();
+        let street = if street.is_empty() {
+            finding(AddressField::Street, PixelletterErrorCode::Code204)
+        } else if PO_BOX_PREFIXES
+            .iter()
+            .any(|prefix| street.starts_with(prefix))
+        {
+            finding(AddressField::Street, PixelletterErrorCode::Code205)
+        } else {
+            None
+        };
+
+        let postcode = address.postcode.trim();
+        let is_digits = |len| postcode.len() == len && postcode.bytes().all(|b| b.is_ascii_digit());
+        let postcode = if postcode.is_empty() {
+            finding(AddressField::Postcode, PixelletterErrorCode::Code206)
+        } else {
+            match address.country {
+                CountryCode::DEU if !is_digits(5) => {
+                    finding(AddressField::Postcode, PixelletterErrorCode::Code207)
+                }
+                CountryCode::AUT | CountryCode::CHE if !is_digits(4) => {
+                    finding(AddressField::Postcode, PixelletterErrorCode::Code208)
+                }
+                _ => None,
+            }
+        };
+
+        let city = match address.city.trim().is_empty() {
+            true => finding(AddressField::City, PixelletterErrorCode::Code209),
+            false => None,
+        };
+
+        [name, street, postcode, city]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Fails with the first problem found, like the server would.
+    pub fn validate(&self, address: &Address) -> Result<()> {
+        match self.check(address).into_iter().next() {
+            Some(finding) => Err(Error::Rejected(finding.code)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn german(street: &str, postcode: &str) -> Address {
+        Address::builder()
+            .name("Manfred Müller".to_owned())
+            .street(street.to_owned())
+            .postcode(postcode.to_owned())
+            .city("Berlin".to_owned())
+            .country(CountryCode::DEU)
+            .build()
+    }
+
+    #[test]
+    fn rejects_po_boxes() {
+        for street in ["Postfach 12 34", "PO Box 17", "p.o. box 17"] {
+            assert_eq!(
+                AddressValidator.check(&german(street, "10115")),
+                [AddressFinding {
+                    field: AddressField::Street,
+                    code: PixelletterErrorCode::Code205,
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn german_postcodes_have_5_digits() {
+        assert!(AddressValidator
+            .check(&german("Hauptstraße 1", "10115"))
+            .is_empty());
+
+        for postcode in ["1011", "101155", "1O115"] {
+            assert_eq!(
+                AddressValidator.check(&german("Hauptstraße 1", postcode)),
+                [AddressFinding {
+                    field: AddressField::Postcode,
+                    code: PixelletterErrorCode::Code207,
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn austrian_and_swiss_postcodes_have_4_digits() {
+        for country in [CountryCode::AUT, CountryCode::CHE] {
+            for (postcode, finding) in [("1010", None), ("10100", Some(())), ("A-1010", Some(()))] {
+                let address = Address::builder()
+                    .name("Erika Muster".to_owned())
+                    .street("Ring 2".to_owned())
+                    .postcode(postcode.to_owned())
+                    .city("Wien".to_owned())
+                    .country(country)
+                    .build();
+
+                let expected = finding.map(|()| AddressFinding {
+                    field: AddressField::Postcode,
+                    code: PixelletterErrorCode::Code208,
+                });
+                assert_eq!(AddressValidator.check(&address), Vec::from_iter(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn structured_blocks_are_validated() {
+        let block = AddressBlock::from(german("Postfach 12 34", "10115"));
+        assert!(matches!(
+            block.validate(),
+            Err(Error::Rejected(PixelletterErrorCode::Code205))
+        ));
+
+        let block = AddressBlock::from("Postfach 12 34\n10115 Berlin");
+        assert!(block.validate().is_ok());
+    }
+
+    #[test]
+    fn german_layout() {
+        let address = Address::builder()
+            .name("Manfred Müller".to_owned())
+            .company("Müller GmbH".to_owned())
+            .street("Hauptstraße".to_owned())
+            .house_number("5a".to_owned())
+            .postcode("10115".to_owned())
+            .city("Berlin".to_owned())
+            .country(CountryCode::DEU)
+            .build();
+
+        assert_eq!(
+            address.lines(),
+            [
+                "Müller GmbH",
+                "Manfred Müller",
+                "Hauptstraße 5a",
+                "10115 Berlin"
+            ]
+        );
+    }
+
+    #[test]
+    fn austrian_layout() {
+        let address = Address::builder()
+            .name("Erika Muster".to_owned())
+            .addition("Stiege 2, Tür 14".to_owned())
+            .street("Ringstraße".to_owned())
+            .house_number("12".to_owned())
+            .postcode("1010".to_owned())
+            .city("Wien".to_owned())
+            .country(CountryCode::AUT)
+            .build();
+
+        assert_eq!(
+            address.lines(),
+            [
+                "Erika Muster",
+                "Stiege 2, Tür 14",
+                "Ringstraße 12",
+                "1010 Wien",
+                "ÖSTERREICH"
+            ]
+        );
+    }
+
+    #[test]
+    fn swiss_layout() {
+        let address = Address::builder()
+            .name("Hans Meier".to_owned())
+            .street("Bahnhofstrasse".to_owned())
+            .house_number("1".to_owned())
+            .postcode("8001".to_owned())
+            .city("Zürich".to_owned())
+            .country(CountryCode::CHE)
+            .build();
+
+        assert_eq!(
+            address.lines(),
+            ["Hans Meier", "Bahnhofstrasse 1", "8001 Zürich", "SCHWEIZ"]
+        );
+    }
+
+    #[test]
+    fn international_layouts() {
+        let address = Address::builder()
+            .name("John Doe".to_owned())
+            .street("Fifth Avenue".to_owned())
+            .house_number("350".to_owned())
+            .postcode("10118".to_owned())
+            .city("New York".to_owned())
+            .region("NY".to_owned())
+            .country(CountryCode::USA)
+            .build();
+
+        assert_eq!(
+            address.lines(),
+            ["John Doe", "350 Fifth Avenue", "New York NY 10118", "USA"]
+        );
+
+        let address = Address::builder()
+            .name("Jane Doe".to_owned())
+            .street("Downing Street".to_owned())
+            .house_number("10".to_owned())
+            .postcode("SW1A 2AA".to_owned())
+            .city("London".to_owned())
+            .country(CountryCode::GBR)
+            .build();
+
+        assert_eq!(
+            address.lines(),
+            [
+                "Jane Doe",
+                "10 Downing Street",
+                "London",
+                "SW1A 2AA",
+                "GROSSBRITANNIEN"
+            ]
+        );
+    }
+
+    fn in_country(country: CountryCode) -> Address {
+        Address::builder()
+            .name("Kim".to_owned())
+            .street("Main Street".to_owned())
+            .postcode("1234".to_owned())
+            .city("Capital".to_owned())
+            .country(country)
+            .build()
+    }
+
+    #[test]
+    fn country_lines_stay_distinct() {
+        let pairs = [
+            (CountryCode::KOR, CountryCode::PRK),
+            (CountryCode::COG, CountryCode::COD),
+            (CountryCode::VGB, CountryCode::VIR),
+        ];
+
+        for (a, b) in pairs {
+            assert_ne!(
+                in_country(a).lines().last(),
+                in_country(b).lines().last(),
+                "{a:?} {b:?}"
+            );
+        }
+        assert_eq!(
+            in_country(CountryCode::KOR).lines().last().unwrap(),
+            "SÜDKOREA"
+        );
+    }
+
+    #[test]
+    fn country_line_falls_back_to_full_iso_name() {
+        let address = in_country(CountryCode::BOL);
+
+        assert_eq!(
+            address.lines().last().unwrap(),
+            &CountryCode::BOL.name().to_uppercase()
+        );
+    }
+
+    #[test]
+    fn destination_must_match_structured_address() {
+        let block = AddressBlock::from(in_country(CountryCode::AUT));
+        assert!(block.check_destination(CountryCode::AUT).is_ok());
+        assert!(matches!(
+            block.check_destination(CountryCode::DEU),
+            Err(Error::Validation(_))
+        ));
+
+        let block = AddressBlock::from("Kim\nMain Street\n1234 Capital");
+        assert!(block.check_destination(CountryCode::DEU).is_ok());
+    }
+
+    #[test]
+    fn country_line_depends_on_dispatch_country() {
+        let dispatch = Location::Hausleiten.country();
+
+        assert_eq!(
+            in_country(CountryCode::AUT)
+                .lines_from(dispatch)
+                .last()
+                .unwrap(),
+            "1234 Capital"
+        );
+        assert_eq!(
+            in_country(CountryCode::DEU)
+                .lines_from(dispatch)
+                .last()
+                .unwrap(),
+            "DEUTSCHLAND"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+
+use bon::Builder;
+use isocountry::CountryCode;
+
+use crate::{
+    api::{
+        self, ActionType, Bulk, BulkItem, Command, Location, Options, Order, PixelletterErrorCode,
+        TemplateField,
+    },
+    check_result, Client, Error, Money, Result, API_VERSION,
+};
+
+/// Upper bound for the recipients of one [`BulkOrder`], larger lists have to be split into several
+/// orders to keep the request size and the time until the response arrives manageable.
+pub const BULK_MAX_RECIPIENTS: usize = 5_000;
+
+/// Many template letters sent with a single request (action 5).
+///
+/// Control options, additional services and the location are not available for bulk orders, so
+/// they can't be set here, and the items are numbered by the crate. At most
+/// [`BULK_MAX_RECIPIENTS`] recipients are accepted per order.
+#[derive(Builder)]
+pub struct BulkOrder {
+    template: u32,
+    destination: CountryCode,
+    recipients: Vec<BulkRecipient>,
+    transaction: Option<String>,
+    estimated_cost: Option<Money>,
+}
+
+#[derive(Builder, Debug, Clone)]
+pub struct BulkRecipient {
+    /// Identifies the recipient in the caller's records, it is not sent to the API
+    reference: String,
+    fields: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkReceipt {
+    pub message: String,
+    pub transaction: Option<String>,
+    pub items: Vec<BulkItemResult>,
+
+    /// See [`OrderReceipt::raw`](crate::OrderReceipt::raw), it holds the results of all items
+    pub raw: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkItemResult {
+    pub reference: String,
+    pub outcome: BulkItemOutcome,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BulkItemOutcome {
+    Accepted {
+        order_id: Option<String>,
+    },
+    Rejected(PixelletterErrorCode),
+
+    /// The response contains no result for this item
+    Missing,
+}
+
+impl Client {
+    pub async fn bulk_order(&self, order: BulkOrder) -> Result<BulkReceipt> {
+        if order.recipients.is_empty() {
+            return Err(Error::Validation("`recipients` is empty!".to_owned()));
+        }
+
+        if order.recipients.len() > BULK_MAX_RECIPIENTS {
+            return Err(Error::Validation(format!(
+                "`recipients` holds {} entries, split it into orders of at most {BULK_MAX_RECIPIENTS}!",
+                order.recipients.len()
+            )));
+        }
+
+        if order.template == 0 {
+            return Err(Error::Rejected(PixelletterErrorCode::Code52));
+        }
+
+        let request = api::Pixelletter {
+            version: API_VERSION.to_owned(),
+            auth: Some(self.auth.clone()),
+            command: Some(Command {
+                order: Some(Order {
+                    content_type: "template".to_owned(),
+
+                    options: Options {
+                        action: Some(ActionType::Bulk),
+                        transaction: order.transaction.clone(),
+                        location: Some(Location::Munich),
+                        destination: Some(order.destination.alpha2().to_owned()),
+                        ..Default::default()
+                    },
+
+                    template: Some(api::Template {
+                        number: order.template,
+                        fields: Vec::new(),
+                    }),
+
+                    bulk: Some(Bulk {
+                        items: (1..)
+                            .zip(order.recipients.iter())
+                            .map(|(no, recipient)| BulkItem {
+                                no,
+                                fields: recipient
+                                    .fields
+                                    .iter()
+                                    .map(|(name, value)| TemplateField {
+                                        name: name.to_owned(),
+                                        value: value.to_owned(),
+                                    })
+                                    .collect(),
+                            })
+                            .collect(),
+                    }),
+
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        self.ensure_credit(order.estimated_cost).await?;
+
+        let raw = self.send_raw(&request, Vec::new()).await?;
+        let resp = quick_xml::de::from_str::<api::Pixelletter>(&raw)?
+            .response
+            .ok_or(Error::MissingField("response"))?;
+
+        let message = check_result(resp.result).inspect_err(|err| self.forget_credit(err))?;
+
+        if let Some(cost) = order.estimated_cost {
+            self.deduct_cached_credit(cost);
+        }
+
+        let items = (1..)
+            .zip(order.recipients)
+            .map(|(no, recipient)| BulkItemResult {
+                reference: recipient.reference,
+                outcome: match resp.items.iter().find(|item| item.no == no) {
+                    Some(item) if item.result.code == 100 => BulkItemOutcome::Accepted {
+                        order_id: item.id.clone(),
+                    },
+                    Some(item) => BulkItemOutcome::Rejected(PixelletterErrorCode::new(
+                        item.result.code,
+                        item.result.msg.clone(),
+                    )),
+                    None => BulkItemOutcome::Missing,
+                },
+            })
+            .collect();
+
+        Ok(BulkReceipt {
+            message,
+            transaction: resp.transaction.filter(|echoed| !echoed.is_empty()),
+            items,
+            raw,
+        })
+    }
+}
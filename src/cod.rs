@@ -0,0 +1,151 @@
+use bon::Builder;
+
+use crate::{api, api::PixelletterErrorCode, Currency, Error, Money, Result};
+
+const MAX_LINE_CHARS: usize = 27;
+const MIN_AMOUNT_CENTS: i64 = 300; // 3,00 EUR
+const MAX_AMOUNT_CENTS: i64 = 160_000; // 1600,00 EUR
+
+/// Cash on delivery (Nachnahme): the recipient pays `amount` on delivery, which is transferred to
+/// the beneficiary's account.
+#[derive(Builder, Debug, Clone, PartialEq)]
+pub struct CashOnDelivery {
+    beneficiary: String,
+    bank_name: String,
+    purpose_line1: Option<String>,
+    purpose_line2: String,
+    amount: Money,
+    account_number: String,
+    bank_code: String,
+}
+
+impl CashOnDelivery {
+    // Codes 30 to 37, see `is_valid_line` for the allowed characters
+    pub fn validate(&self) -> Result<()> {
+        let purpose_line1 = self.purpose_line1.as_deref().unwrap_or_default();
+
+        let code = if !is_valid_line(&self.beneficiary, 1) {
+            PixelletterErrorCode::Code30
+        } else if !is_valid_line(&self.bank_name, 1) {
+            PixelletterErrorCode::Code31
+        } else if !is_valid_line(purpose_line1, 0) {
+            PixelletterErrorCode::Code32
+        } else if !is_valid_line(&self.purpose_line2, 1) {
+            PixelletterErrorCode::Code33
+        } else if self.amount.currency() != Currency::EUR
+            || !(MIN_AMOUNT_CENTS..=MAX_AMOUNT_CENTS).contains(&self.amount.cents())
+        {
+            PixelletterErrorCode::Code35
+        } else if !is_digits(&self.account_number, 6..=10) {
+            PixelletterErrorCode::Code36
+        } else if !is_digits(&self.bank_code, 8..=8) {
+            PixelletterErrorCode::Code37
+        } else {
+            return Ok(());
+        };
+
+        Err(Error::Rejected(code))
+    }
+}
+
+impl From<CashOnDelivery> for api::CashOnDelivery {
+    fn from(cod: CashOnDelivery) -> Self {
+        Self {
+            name: cod.beneficiary,
+            bank: cod.bank_name,
+            purpose1: cod.purpose_line1.unwrap_or_default(),
+            purpose2: cod.purpose_line2,
+            amount: cod.amount.to_german_string(),
+            account_number: cod.account_number,
+            bank_code: cod.bank_code,
+        }
+    }
+}
+
+fn is_valid_line(value: &str, min_chars: usize) -> bool {
+    (min_chars..=MAX_LINE_CHARS).contains(&value.chars().count())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase() || "äöüß.,&-/+*$% ".contains(c))
+}
+
+fn is_digits(value: &str, len: std::ops::RangeInclusive<usize>) -> bool {
+    len.contains(&value.len()) && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cod(purpose_line1: Option<&str>, purpose_line2: &str, amount: Money) -> CashOnDelivery {
+        CashOnDelivery::builder()
+            .beneficiary("MUELLER GMBH".to_owned())
+            .bank_name("SPARKASSE BERLIN".to_owned())
+            .maybe_purpose_line1(purpose_line1.map(str::to_owned))
+            .purpose_line2(purpose_line2.to_owned())
+            .amount(amount)
+            .account_number("1234567890".to_owned())
+            .bank_code("10050000".to_owned())
+            .build()
+    }
+
+    #[test]
+    fn line_length_limit() {
+        let line = "R".repeat(MAX_LINE_CHARS);
+        assert!(cod(Some(&line), &line, Money::eur(1000)).validate().is_ok());
+
+        let line = "R".repeat(MAX_LINE_CHARS + 1);
+        assert!(matches!(
+            cod(Some(&line), "RECHNUNG 4711", Money::eur(1000)).validate(),
+            Err(Error::Rejected(PixelletterErrorCode::Code32))
+        ));
+        assert!(matches!(
+            cod(None, &line, Money::eur(1000)).validate(),
+            Err(Error::Rejected(PixelletterErrorCode::Code33))
+        ));
+    }
+
+    #[test]
+    fn purpose_line1_may_be_empty() {
+        assert!(cod(None, "RECHNUNG 4711", Money::eur(1000))
+            .validate()
+            .is_ok());
+        assert!(cod(Some(""), "RECHNUNG 4711", Money::eur(1000))
+            .validate()
+            .is_ok());
+        assert!(matches!(
+            cod(None, "", Money::eur(1000)).validate(),
+            Err(Error::Rejected(PixelletterErrorCode::Code33))
+        ));
+    }
+
+    #[test]
+    fn allowed_characters() {
+        assert!(matches!(
+            cod(None, "MÜLLER STRAßE 1/2 äöü", Money::eur(1000)).validate(),
+            Err(Error::Rejected(PixelletterErrorCode::Code33))
+        ));
+        assert!(cod(None, "MUELLER STRAßE 1/2 äöü", Money::eur(1000))
+            .validate()
+            .is_ok());
+        assert!(matches!(
+            cod(None, "rechnung", Money::eur(1000)).validate(),
+            Err(Error::Rejected(PixelletterErrorCode::Code33))
+        ));
+    }
+
+    #[test]
+    fn amount_bounds() {
+        // 3,00 EUR and 1600,00 EUR
+        for cents in [MIN_AMOUNT_CENTS, MAX_AMOUNT_CENTS] {
+            assert!(cod(None, "RECHNUNG", Money::eur(cents)).validate().is_ok());
+        }
+
+        for cents in [MIN_AMOUNT_CENTS - 1, MAX_AMOUNT_CENTS + 1] {
+            assert!(matches!(
+                cod(None, "RECHNUNG", Money::eur(cents)).validate(),
+                Err(Error::Rejected(PixelletterErrorCode::Code35))
+            ));
+        }
+    }
+}
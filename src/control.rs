@@ -0,0 +1,77 @@
+use bon::Builder;
+
+use crate::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintMode {
+    Simplex,
+    Duplex,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverPage {
+    Print,
+
+    /// The first page of the document is not printed
+    Skip,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressPage {
+    /// The recipient address is read from the address field on the first page of the document
+    FromDocument,
+
+    /// An additional page carrying the recipient address is put in front of the document
+    Separate,
+}
+
+/// Print and processing switches of an order, serialized into the `control` element.
+///
+/// Every switch left unset keeps the account default.
+#[derive(Builder, Default, Debug, Clone, PartialEq, Eq)]
+pub struct Control {
+    print_mode: Option<PrintMode>,
+    cover_page: Option<CoverPage>,
+    address_page: Option<AddressPage>,
+}
+
+impl Control {
+    pub fn validate(&self) -> Result<()> {
+        if self.cover_page == Some(CoverPage::Skip)
+            && self.address_page == Some(AddressPage::FromDocument)
+        {
+            return Err(Error::Validation(
+                "The address can't be read from a cover page that is skipped!".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn skips_cover_page(&self) -> bool {
+        self.cover_page == Some(CoverPage::Skip)
+    }
+
+    pub(crate) fn to_control_string(&self) -> String {
+        let print_mode = self.print_mode.map(|mode| match mode {
+            PrintMode::Simplex => "simplex",
+            PrintMode::Duplex => "duplex",
+        });
+
+        let cover_page = self.cover_page.map(|cover| match cover {
+            CoverPage::Print => "cover",
+            CoverPage::Skip => "nocover",
+        });
+
+        let address_page = self.address_page.map(|address| match address {
+            AddressPage::FromDocument => "noaddresspage",
+            AddressPage::Separate => "addresspage",
+        });
+
+        [print_mode, cover_page, address_page]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<&str>>()
+            .join(",")
+    }
+}
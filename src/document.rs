@@ -0,0 +1,46 @@
+use bon::Builder;
+use reqwest::multipart::Part;
+
+use crate::Result;
+
+const DEFAULT_MIME: &str = "application/pdf";
+
+/// A file attached to an order, kept as plain bytes so it can be encrypted before the upload.
+#[derive(Builder, Debug, Clone, PartialEq)]
+pub struct Document {
+    file_name: String,
+    bytes: Vec<u8>,
+
+    /// Defaults to `application/pdf`
+    mime: Option<String>,
+}
+
+impl Document {
+    pub fn pdf(file_name: String, bytes: Vec<u8>) -> Self {
+        Self {
+            file_name,
+            bytes,
+            mime: None,
+        }
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn mime(&self) -> &str {
+        self.mime.as_deref().unwrap_or(DEFAULT_MIME)
+    }
+
+    pub(crate) fn into_part(self) -> Result<Part> {
+        let mime = self.mime().to_owned();
+
+        Ok(Part::bytes(self.bytes)
+            .file_name(self.file_name)
+            .mime_str(&mime)?)
+    }
+}
@@ -0,0 +1,67 @@
+use thiserror::Error;
+
+use crate::{
+    api::PixelletterErrorCode,
+    money::{Money, ParseMoneyError},
+};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+// Non-exhaustive, as the `pgp` feature adds variants and features have to stay additive
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The request could not be sent or the server answered with an HTTP error status.
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+
+    /// The request could not be serialized or the response could not be parsed.
+    #[error(transparent)]
+    Xml(#[from] quick_xml::DeError),
+
+    /// A document could not be encrypted or the public key could not be read.
+    #[cfg(feature = "pgp")]
+    #[error(transparent)]
+    Pgp(#[from] ::pgp::errors::Error),
+
+    /// The public key file could not be read.
+    #[cfg(feature = "pgp")]
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// A monetary amount in the response is not in the expected format.
+    #[error(transparent)]
+    Money(#[from] ParseMoneyError),
+
+    /// The request was rejected locally, nothing has been sent.
+    #[error("{0}")]
+    Validation(String),
+
+    /// The request would be rejected by the API with the contained code, nothing has been sent.
+    #[error("{0}")]
+    Rejected(PixelletterErrorCode),
+
+    /// Electronic signatures have to be activated once in the customer area (code 46).
+    #[error("Electronic signatures are not activated for this account")]
+    SignatureNotActivated,
+
+    /// Premiumadress credentials have not been stored for this account by support (code 88).
+    #[error("Premiumadress is not configured for this account")]
+    PremiumadressNotConfigured,
+
+    /// The credit guard found the account balance too low, nothing has been sent.
+    #[error("Insufficient credit: {available} available, but {required} required")]
+    InsufficientCredit { available: Money, required: Money },
+
+    /// The response is missing a field that is required to interpret it.
+    #[error("No `{0}` field")]
+    MissingField(&'static str),
+
+    /// The API answered with a result code other than `100`.
+    #[error("{error}")]
+    Api {
+        error: PixelletterErrorCode,
+        code: i32,
+        msg: String,
+    },
+}
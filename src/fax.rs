@@ -0,0 +1,228 @@
+use std::{fmt, str::FromStr};
+
+use isocountry::CountryCode;
+
+use crate::{api::PixelletterErrorCode, Error, Result};
+
+// E.164 allows at most 15 digits, anything shorter than 7 can not be a reachable fax line
+const MIN_DIGITS: usize = 7;
+const MAX_DIGITS: usize = 15;
+
+// Calling code and trunk prefix dialed in front of national numbers
+const CALLING_CODES: &[(CountryCode, &str, Option<&str>)] = &[
+    (CountryCode::AUT, "43", Some("0")),
+    (CountryCode::BEL, "32", Some("0")),
+    (CountryCode::BGR, "359", Some("0")),
+    (CountryCode::CAN, "1", Some("1")),
+    (CountryCode::CHE, "41", Some("0")),
+    (CountryCode::CYP, "357", None),
+    (CountryCode::CZE, "420", None),
+    (CountryCode::DEU, "49", Some("0")),
+    (CountryCode::DNK, "45", None),
+    (CountryCode::ESP, "34", None),
+    (CountryCode::EST, "372", None),
+    (CountryCode::FIN, "358", Some("0")),
+    (CountryCode::FRA, "33", Some("0")),
+    (CountryCode::GBR, "44", Some("0")),
+    (CountryCode::GRC, "30", None),
+    (CountryCode::HRV, "385", Some("0")),
+    (CountryCode::HUN, "36", Some("06")),
+    (CountryCode::IRL, "353", Some("0")),
+    (CountryCode::ITA, "39", None),
+    (CountryCode::LIE, "423", None),
+    (CountryCode::LTU, "370", Some("8")),
+    (CountryCode::LUX, "352", None),
+    (CountryCode::LVA, "371", None),
+    (CountryCode::MLT, "356", None),
+    (CountryCode::NLD, "31", Some("0")),
+    (CountryCode::NOR, "47", None),
+    (CountryCode::POL, "48", None),
+    (CountryCode::PRT, "351", None),
+    (CountryCode::ROU, "40", Some("0")),
+    (CountryCode::SVK, "421", Some("0")),
+    (CountryCode::SVN, "386", Some("0")),
+    (CountryCode::SWE, "46", Some("0")),
+    (CountryCode::TUR, "90", Some("0")),
+    (CountryCode::USA, "1", Some("1")),
+];
+
+/// A fax number normalized to E.164, e.g. `+4915228817386`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FaxNumber(String);
+
+impl FaxNumber {
+    /// Parses an international number (`+49 …` or `0049 …`) or a national number of
+    /// `default_country`.
+    ///
+    /// Spaces, dashes, slashes, dots and parentheses are ignored, as is a trunk prefix written after
+    /// the calling code (`+49 (0) 30 …`). A number that is still invalid is rejected with code 18, a
+    /// national number of an unsupported `default_country` with a validation error.
+    pub fn parse(input: &str, default_country: CountryCode) -> Result<Self> {
+        Self::parse_with(input, Some(default_country))
+    }
+
+    /// The number in E.164 format, as it is sent to the API.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn parse_with(input: &str, default_country: Option<CountryCode>) -> Result<Self> {
+        let invalid = || Error::Rejected(PixelletterErrorCode::Code18);
+
+        // "+49 (0) 30 …" marks the trunk prefix that is only dialed within the country
+        let input = input.trim().replace("(0)", "");
+        let (international, rest) = match input.strip_prefix('+') {
+            Some(rest) => (true, rest),
+            None => (false, input.as_str()),
+        };
+
+        let mut digits = String::with_capacity(rest.len());
+        for c in rest.chars() {
+            match c {
+                '0'..='9' => digits.push(c),
+                ' ' | '-' | '/' | '.' | '(' | ')' => {}
+                _ => return Err(invalid()),
+            }
+        }
+
+        let digits = if international {
+            strip_trunk_prefix(digits)
+        } else if let Some(rest) = digits.strip_prefix("00") {
+            strip_trunk_prefix(rest.to_owned())
+        } else {
+            let Some(default_country) = default_country else {
+                return Err(invalid());
+            };
+            let (_, calling_code, trunk_prefix) = CALLING_CODES
+                .iter()
+                .find(|(country, ..)| *country == default_country)
+                .ok_or_else(|| {
+                    Error::Validation(format!(
+                        "National fax numbers of {} are not supported, use the international format!",
+                        default_country.name()
+                    ))
+                })?;
+
+            // The trunk prefix is often left out, e.g. for numbers in North America
+            let national = trunk_prefix
+                .and_then(|prefix| digits.strip_prefix(prefix))
+                .unwrap_or(&digits);
+            format!("{calling_code}{national}")
+        };
+
+        if !(MIN_DIGITS..=MAX_DIGITS).contains(&digits.len()) || digits.starts_with('0') {
+            return Err(invalid());
+        }
+
+        Ok(Self(format!("+{digits}")))
+    }
+}
+
+// Drops a trunk prefix written after the calling code, e.g. "+49 030 …". Only prefixes starting with
+// 0 are dropped, other digits can be the start of a valid national number.
+fn strip_trunk_prefix(digits: String) -> String {
+    CALLING_CODES
+        .iter()
+        .find_map(|(_, calling_code, trunk_prefix)| {
+            let trunk_prefix = trunk_prefix.filter(|prefix| prefix.starts_with('0'))?;
+            let national = digits
+                .strip_prefix(calling_code)?
+                .strip_prefix(trunk_prefix)?;
+            Some(format!("{calling_code}{national}"))
+        })
+        .unwrap_or(digits)
+}
+
+/// Only accepts international numbers, use [`FaxNumber::parse`] for national ones.
+impl FromStr for FaxNumber {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse_with(s, None)
+    }
+}
+
+impl fmt::Display for FaxNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<FaxNumber> for String {
+    fn from(number: FaxNumber) -> Self {
+        number.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Result<String> {
+        FaxNumber::parse(input, CountryCode::DEU).map(String::from)
+    }
+
+    #[test]
+    fn normalizes_international_and_national_numbers() {
+        for input in [
+            "+49 152 28817386",
+            "0049 (152) 288-17386",
+            "0152 / 288 173 86",
+            "+49 (0) 152 28817386",
+            "+49 0152 28817386",
+        ] {
+            assert_eq!(parse(input).unwrap(), "+4915228817386", "{input}");
+        }
+
+        assert_eq!(parse("+49 (0) 30 1234567").unwrap(), "+49301234567");
+        assert_eq!(parse("+43 (0)1 5551234").unwrap(), "+4315551234");
+        assert_eq!(
+            FaxNumber::parse("06 1234567", CountryCode::ITA)
+                .unwrap()
+                .as_str(),
+            "+39061234567"
+        );
+        assert_eq!(
+            FaxNumber::parse("(212) 555-0123", CountryCode::USA)
+                .unwrap()
+                .as_str(),
+            "+12125550123"
+        );
+    }
+
+    #[test]
+    fn international_only_from_str() {
+        assert_eq!(
+            "+49 152 28817386".parse::<FaxNumber>().unwrap().as_str(),
+            "+4915228817386"
+        );
+        assert!("0152 28817386".parse::<FaxNumber>().is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_numbers() {
+        for input in [
+            "",
+            "+",
+            "+49 abc",
+            "0152-2881738x",
+            "+0 123456789",
+            "123",
+            "+49 1234567890123456",
+        ] {
+            assert!(
+                matches!(
+                    parse(input),
+                    Err(Error::Rejected(PixelletterErrorCode::Code18))
+                ),
+                "{input}"
+            );
+        }
+
+        assert!(matches!(
+            FaxNumber::parse("030 1234567", CountryCode::JPN),
+            Err(Error::Validation(_))
+        ));
+        assert!(FaxNumber::parse("+81 3 12345678", CountryCode::JPN).is_ok());
+    }
+}
@@ -1,19 +1,63 @@
-use anyhow::{bail, Context};
-use api::{error_code_to_msg, ActionType, Command, Options, Order};
+use api::{
+    ActionType, Command, CostumerData, Info, Options, Order, OrderStatus, PixelletterErrorCode,
+    ResponseResult,
+};
+use std::{
+    collections::BTreeMap,
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime},
+};
+
 use bon::{bon, Builder};
 use isocountry::CountryCode;
-use reqwest::multipart::{Form, Part};
+use reqwest::multipart::Form;
 
 use crate::api::{Addoption, Auth, Location};
 
+pub use address::{Address, AddressBlock, AddressField, AddressFinding, AddressValidator};
+pub use bulk::{
+    BulkItemOutcome, BulkItemResult, BulkOrder, BulkReceipt, BulkRecipient, BULK_MAX_RECIPIENTS,
+};
+pub use cod::CashOnDelivery;
+pub use control::{AddressPage, Control, CoverPage, PrintMode};
+pub use document::Document;
+pub use error::{Error, Result};
+pub use fax::FaxNumber;
+pub use money::{Currency, Money, ParseMoneyError};
+pub use non_empty::NonEmptyVec;
+#[cfg(feature = "pgp")]
+pub use pgp::PgpKey;
+pub use postcard::{Postcard, POSTCARD_MAX_ADDRESS_LINES, POSTCARD_MAX_PHOTO_BYTES};
+pub use signature::SignatureOrder;
+
+mod address;
 pub mod api;
+mod bulk;
+mod cod;
+mod control;
+mod document;
+mod error;
+mod fax;
+mod money;
+mod non_empty;
+#[cfg(feature = "pgp")]
+mod pgp;
+mod postcard;
+mod signature;
 
+const API_VERSION: &str = "1.3";
 const BASE_URL: &str = "https://www.pixelletter.de/xml/index.php";
 const XML_HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#;
 
 pub struct Client {
     client: reqwest::Client,
+    base_url: String,
     auth: Auth,
+    credit_guard: Option<CreditGuard>,
+    credit_cache: Mutex<Option<(Instant, Money)>>,
+
+    #[cfg(feature = "pgp")]
+    pgp_key: Option<PgpKey>,
 }
 
 #[bon]
@@ -21,14 +65,22 @@ impl Client {
     #[builder]
     pub fn new(
         client: Option<reqwest::Client>,
+        base_url: Option<String>,
         email: String,
         password: String,
         agb: bool,
         widerrufsverzicht: bool,
         testing_mode: Option<bool>,
+        credit_guard: Option<CreditGuard>,
+        #[cfg(feature = "pgp")] pgp_key: Option<PgpKey>,
     ) -> Self {
         Client {
             client: client.unwrap_or_default(),
+            base_url: base_url.unwrap_or_else(|| BASE_URL.to_owned()),
+            credit_guard,
+            credit_cache: Mutex::new(None),
+            #[cfg(feature = "pgp")]
+            pgp_key,
             auth: Auth {
                 email,
                 password,
@@ -39,53 +91,104 @@ impl Client {
             },
         }
     }
+}
 
+#[bon]
+impl Client {
     #[builder(finish_fn = submit)]
     pub async fn order(
         &self,
-        letter: Option<Letter>,
-        fax: Option<String>,
-        files: Option<Vec<Part>>,
-        text: Option<Text>,
+        #[builder(into)] kind: OrderKind,
+        #[builder(into)] content: Content,
+        sender: Option<SenderProfile>,
         transaction: Option<String>,
-    ) -> anyhow::Result<String> {
-        if letter.is_none() && fax.is_none() {
-            bail!("Neither `letter` nor `fax` are set!");
+        control: Option<Control>,
+        estimated_cost: Option<Money>,
+    ) -> Result<OrderReceipt> {
+        if let Content::Url(url) = &content {
+            validate_document_url(url)?;
+        }
+
+        if let Some(sender) = sender.as_ref() {
+            sender.validate()?;
+
+            if matches!(&content, Content::Text(txt) if txt.return_address.is_some()) {
+                return Err(Error::Validation(
+                    "Set either `sender` or the return address of `text`!".to_owned(),
+                ));
+            }
+        }
+
+        if let Content::Text(txt) = &content {
+            txt.address.validate()?;
+        }
+
+        if let Content::Template(tmpl) = &content {
+            if tmpl.number == 0 {
+                return Err(Error::Rejected(PixelletterErrorCode::Code52));
+            }
         }
 
-        if !(files.is_none() ^ text.is_none()) {
-            bail!("Set either `files` or `text`!");
+        if let Some(control) = control.as_ref() {
+            control.validate()?;
+
+            if matches!(content, Content::Text(_)) && control.skips_cover_page() {
+                return Err(Error::Validation(
+                    "Text orders have no cover page to skip!".to_owned(),
+                ));
+            }
         }
 
-        if files.as_ref().map_or(false, Vec::is_empty) {
-            bail!("`files` is empty!");
+        let action = kind.action();
+        let (letter, fax) = match kind {
+            OrderKind::Letter(letter) => (Some(letter), None),
+            OrderKind::Fax(fax) => (None, Some(fax)),
+            OrderKind::LetterAndFax(letter, fax) => (Some(letter), Some(fax)),
+        };
+
+        if let Some(cod) = letter
+            .as_ref()
+            .and_then(|lttr| lttr.cash_on_delivery.as_ref())
+        {
+            cod.validate()?;
         }
 
+        if let (Some(lttr), Content::Text(txt)) = (letter.as_ref(), &content) {
+            txt.address.check_destination(lttr.destination)?;
+        }
+
+        let dispatch_country = letter
+            .as_ref()
+            .and_then(|lttr| lttr.location.clone())
+            .unwrap_or_default()
+            .country();
+
+        let content_type = content.content_type();
+        let (files, text, template, document_url) = match content {
+            Content::Upload(files) => (files.into_vec(), None, None, None),
+            Content::Text(text) => (Vec::new(), Some(text), None, None),
+            Content::Template(template) => (Vec::new(), None, Some(template), None),
+            Content::Url(url) => (Vec::new(), None, None, Some(url)),
+        };
+
         let letter = crate::api::Pixelletter {
-            version: "1.3".to_owned(),
+            version: API_VERSION.to_owned(),
 
             auth: Some(self.auth.clone()),
 
             command: Some(Command {
                 order: Some(Order {
-                    content_type: match (files.as_ref(), text.as_ref()) {
-                        (Some(_), None) => "upload",
-                        (None, Some(_)) => "text",
-                        (Some(_), Some(_)) | (None, None) => unreachable!(),
-                    }
-                    .to_owned(),
+                    content_type: content_type.to_owned(),
 
                     options: Options {
-                        action: match (letter.as_ref(), fax.as_ref()) {
-                            (Some(_), None) => ActionType::Letter,
-                            (None, Some(_)) => ActionType::Fax,
-                            (Some(_), Some(_)) => ActionType::LetterAndFax,
-                            (None, None) => unreachable!(),
-                        },
+                        action: Some(action),
 
                         transaction,
-                        control: "".to_owned(), // TODO: Implement control options
-                        fax,
+                        control: control
+                            .as_ref()
+                            .map(Control::to_control_string)
+                            .unwrap_or_default(),
+                        fax: fax.map(String::from),
                         location: letter.as_ref().and_then(|lttr| lttr.location.to_owned()),
 
                         destination: letter
@@ -97,22 +200,53 @@ impl Client {
                             .and_then(|lttr| lttr.services.to_owned())
                             .unwrap_or_default(),
 
-                        font: text.as_ref().map(|txt| txt.font.to_owned()),
+                        cash_on_delivery: letter
+                            .as_ref()
+                            .and_then(|lttr| lttr.cash_on_delivery.to_owned())
+                            .map(api::CashOnDelivery::from),
 
-                        returnaddress: text
+                        premiumadress: letter
                             .as_ref()
-                            .map(|txt| txt.return_address.to_owned())
-                            .unwrap_or_default(),
+                            .and_then(|lttr| lttr.premiumadress)
+                            .unwrap_or(false),
+
+                        font: text.as_ref().map(|txt| txt.font.to_owned()),
+
+                        sendernr: match sender.as_ref() {
+                            Some(SenderProfile::Stored(number)) => Some(*number),
+                            _ => None,
+                        },
+
+                        returnaddress: match sender {
+                            Some(SenderProfile::Inline(address)) => address,
+                            _ => text
+                                .as_ref()
+                                .and_then(|txt| txt.return_address.to_owned())
+                                .unwrap_or_default(),
+                        },
                     },
 
                     text: text.map(|txt| api::Text {
-                        address: txt.address,
+                        address: txt.address.into_lines(dispatch_country),
                         message: txt.message,
                     }),
+
+                    template: template.map(|tmpl| api::Template {
+                        number: tmpl.number,
+                        fields: tmpl
+                            .fields
+                            .into_iter()
+                            .flatten()
+                            .map(|(name, value)| api::TemplateField { name, value })
+                            .collect(),
+                    }),
+
+                    url: document_url,
+                    bulk: None,
+                    signature: None,
                 }),
 
-                info: None,
-                id: None,
+                ..Default::default()
             }),
 
             response: None,
@@ -121,49 +255,740 @@ impl Client {
             costumer_credit: None,
         };
 
+        self.submit_order(&letter, files, estimated_cost)
+            .await
+            .map_err(|err| match err {
+                Error::Api { code: 88, .. } => Error::PremiumadressNotConfigured,
+                other => other,
+            })
+    }
+
+    pub async fn account_info(&self) -> Result<Account> {
+        let request = crate::api::Pixelletter {
+            version: API_VERSION.to_owned(),
+            auth: Some(self.auth.clone()),
+            command: Some(Command {
+                info: Some(Info {
+                    account_info: api::AccountInfo {
+                        account_info_type: "all".to_owned(),
+                    },
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let resp = self.send(&request, Vec::new()).await?;
+        if let Some(response) = resp.response {
+            check_result(response.result)?;
+        }
+
+        Ok(Account {
+            id: resp.costumer_id.ok_or(Error::MissingField("id"))?,
+            data: resp.costumer_data.ok_or(Error::MissingField("data"))?,
+            credit: Money::try_from(&resp.costumer_credit.ok_or(Error::MissingField("credit"))?)?,
+        })
+    }
+
+    pub async fn credit(&self) -> Result<Money> {
+        Ok(self.account_info().await?.credit)
+    }
+
+    async fn ensure_credit(&self, estimated_cost: Option<Money>) -> Result<()> {
+        let Some(guard) = &self.credit_guard else {
+            return Ok(());
+        };
+
+        let required = match estimated_cost {
+            Some(cost) => guard.minimum.checked_add(cost).ok_or_else(|| {
+                Error::Validation(
+                    "`estimated_cost` and the credit guard minimum use different currencies!"
+                        .to_owned(),
+                )
+            })?,
+            None => guard.minimum,
+        };
+
+        let available = self.cached_credit(guard.ttl).await?;
+        let ordering = available.partial_cmp(&required).ok_or_else(|| {
+            Error::Validation(
+                "The account credit and the credit guard minimum use different currencies!"
+                    .to_owned(),
+            )
+        })?;
+        if ordering.is_lt() {
+            return Err(Error::InsufficientCredit {
+                available,
+                required,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn cached_credit(&self, ttl: Duration) -> Result<Money> {
+        if let Some((fetched_at, credit)) = *self.credit_cache.lock().unwrap() {
+            if fetched_at.elapsed() < ttl {
+                return Ok(credit);
+            }
+        }
+
+        let credit = self.credit().await?;
+        *self.credit_cache.lock().unwrap() = Some((Instant::now(), credit));
+
+        Ok(credit)
+    }
+
+    fn forget_credit(&self, err: &Error) {
+        if matches!(err, Error::Api { code: 21, .. }) {
+            *self.credit_cache.lock().unwrap() = None;
+        }
+    }
+
+    // Keeps the cached balance roughly in line with the account during a batch of orders
+    fn deduct_cached_credit(&self, cost: Money) {
+        if let Some((_, credit)) = self.credit_cache.lock().unwrap().as_mut() {
+            if let Some(remaining) = credit.checked_sub(cost) {
+                *credit = remaining;
+            }
+        }
+    }
+
+    pub async fn order_status(&self, id: String) -> Result<OrderStatusReport> {
+        let request = self.order_command("status", id.clone());
+
+        let raw = self.send_raw(&request, Vec::new()).await?;
+        let resp = quick_xml::de::from_str::<crate::api::Pixelletter>(&raw)?
+            .response
+            .ok_or(Error::MissingField("response"))?;
+        check_result(resp.result)?;
+
+        Ok(OrderStatusReport {
+            id,
+            status: resp.status.ok_or(Error::MissingField("status"))?,
+            address_correction: resp.address_correction,
+            raw,
+        })
+    }
+
+    /// Retracts an order that has not been printed yet.
+    ///
+    /// An unknown id (result code 55) is reported as [`CancelOutcome::NotFound`] and an order
+    /// that is already past the point of cancellation as [`CancelOutcome::TooLate`], all other
+    /// result codes are returned as [`Error::Api`]. A response without the new status is not
+    /// taken as a cancellation, it fails with [`Error::MissingField`].
+    pub async fn cancel_order(&self, id: String) -> Result<CancelOutcome> {
+        let request = self.order_command("cancel", id);
+
+        let resp = self
+            .send(&request, Vec::new())
+            .await?
+            .response
+            .ok_or(Error::MissingField("response"))?;
+
+        if resp.result.code == 55 {
+            return Ok(CancelOutcome::NotFound);
+        }
+        check_result(resp.result)?;
+
+        Ok(match resp.status.ok_or(Error::MissingField("status"))? {
+            OrderStatus::Cancelled => CancelOutcome::Cancelled,
+            status => CancelOutcome::TooLate(status),
+        })
+    }
+
+    fn order_command(&self, command_type: &str, id: String) -> crate::api::Pixelletter {
+        crate::api::Pixelletter {
+            version: API_VERSION.to_owned(),
+            auth: Some(self.auth.clone()),
+            command: Some(Command {
+                command_type: Some(command_type.to_owned()),
+                id: Some(id),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    async fn submit_order(
+        &self,
+        request: &crate::api::Pixelletter,
+        files: Vec<Document>,
+        estimated_cost: Option<Money>,
+    ) -> Result<OrderReceipt> {
+        self.ensure_credit(estimated_cost).await?;
+
+        let submitted_at = SystemTime::now();
+        let raw = self.send_raw(request, files).await?;
+        let resp = quick_xml::de::from_str::<crate::api::Pixelletter>(&raw)?
+            .response
+            .ok_or(Error::MissingField("response"))?;
+
+        let code = resp.result.code;
+        let message = check_result(resp.result).inspect_err(|err| self.forget_credit(err))?;
+
+        if let Some(cost) = estimated_cost {
+            self.deduct_cached_credit(cost);
+        }
+
+        Ok(OrderReceipt {
+            code,
+            message,
+            transaction: resp.transaction.filter(|echoed| !echoed.is_empty()),
+            sent_transaction: request
+                .command
+                .as_ref()
+                .and_then(|cmd| cmd.order.as_ref())
+                .and_then(|order| order.options.transaction.clone()),
+            order_id: resp.id,
+            submitted_at,
+            raw,
+        })
+    }
+
+    async fn send(
+        &self,
+        request: &crate::api::Pixelletter,
+        files: Vec<Document>,
+    ) -> Result<crate::api::Pixelletter> {
+        let resp_text = self.send_raw(request, files).await?;
+
+        Ok(quick_xml::de::from_str::<crate::api::Pixelletter>(
+            &resp_text,
+        )?)
+    }
+
+    async fn send_raw(
+        &self,
+        request: &crate::api::Pixelletter,
+        files: Vec<Document>,
+    ) -> Result<String> {
+        if let Some(order) = request.command.as_ref().and_then(|cmd| cmd.order.as_ref()) {
+            order.validate().map_err(Error::Rejected)?;
+        }
+
         let mut form = Form::new().text(
             "xml",
-            format!("{XML_HEADER}{}", quick_xml::se::to_string(&letter)?),
+            format!("{XML_HEADER}{}", quick_xml::se::to_string(request)?),
         );
 
         // Attach files to upload
-        for (index, attachment) in files.into_iter().flatten().enumerate() {
-            form = form.part(format!("uploadfile{index}"), attachment);
+        for (index, document) in files.into_iter().enumerate() {
+            #[cfg(feature = "pgp")]
+            let document = match self.pgp_key.as_ref() {
+                Some(key) => key.encrypt(document)?,
+                None => document,
+            };
+
+            form = form.part(format!("uploadfile{index}"), document.into_part()?);
         }
 
-        let resp_text = self
+        Ok(self
             .client
-            .post(BASE_URL)
+            .post(&self.base_url)
             .multipart(form)
             .send()
             .await?
             .error_for_status()?
             .text()
-            .await?;
+            .await?)
+    }
+}
 
-        let resp = quick_xml::de::from_str::<crate::api::Pixelletter>(&resp_text)?
-            .response
-            .context("No `response` field")?;
+// Code 78, the server can only fetch http and https URLs
+fn validate_document_url(url: &str) -> Result<()> {
+    let is_valid = reqwest::Url::parse(url).is_ok_and(|url| {
+        matches!(url.scheme(), "http" | "https")
+            && url.host_str().is_some_and(|host| !host.is_empty())
+    });
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(Error::Rejected(PixelletterErrorCode::Code78))
+    }
+}
+
+fn check_result(result: ResponseResult) -> Result<String> {
+    if result.code == 100 {
+        Ok(result.msg)
+    } else {
+        Err(Error::Api {
+            error: PixelletterErrorCode::new(result.code, result.msg.clone()),
+            code: result.code,
+            msg: result.msg,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Account {
+    pub id: String,
+    pub data: CostumerData,
+    pub credit: Money,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderReceipt {
+    pub code: i32,
+    pub message: String,
+    pub transaction: Option<String>,
+
+    /// The transaction id sent with the order
+    pub sent_transaction: Option<String>,
 
-        if resp.result.code == 100 {
-            Ok(resp.result.msg)
-        } else {
-            bail!("{}", error_code_to_msg(resp.result.code as u8).unwrap())
+    pub order_id: Option<String>,
+    pub submitted_at: SystemTime,
+
+    /// The unmodified response, kept as proof of submission
+    pub raw: String,
+}
+
+impl OrderReceipt {
+    /// Whether the server echoed a different transaction id than was sent.
+    ///
+    /// The order has been accepted either way, so it must not be submitted again. A response
+    /// without an echo is not a mismatch.
+    pub fn transaction_mismatch(&self) -> bool {
+        match (&self.sent_transaction, &self.transaction) {
+            (Some(sent), Some(echoed)) => sent != echoed,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderStatusReport {
+    pub id: String,
+    pub status: OrderStatus,
+
+    /// Set once Deutsche Post reported a new address for a letter sent with Premiumadress
+    pub address_correction: Option<api::AddressCorrection>,
+
+    /// The unmodified response, for fields this crate does not model yet
+    pub raw: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CancelOutcome {
+    Cancelled,
+
+    /// The order has already progressed too far, it is left in the contained status
+    TooLate(OrderStatus),
+
+    NotFound,
+}
+
+#[derive(Builder, Debug, Clone)]
+pub struct CreditGuard {
+    #[builder(default)]
+    minimum: Money,
+
+    #[builder(default = Duration::from_secs(300))]
+    ttl: Duration,
+}
+
+/// Where an order is delivered to.
+pub enum OrderKind {
+    Letter(Letter),
+    Fax(FaxNumber),
+    LetterAndFax(Letter, FaxNumber),
+}
+
+impl OrderKind {
+    fn action(&self) -> ActionType {
+        match self {
+            Self::Letter(_) => ActionType::Letter,
+            Self::Fax(_) => ActionType::Fax,
+            Self::LetterAndFax(..) => ActionType::LetterAndFax,
+        }
+    }
+}
+
+impl From<Letter> for OrderKind {
+    fn from(letter: Letter) -> Self {
+        Self::Letter(letter)
+    }
+}
+
+impl From<FaxNumber> for OrderKind {
+    fn from(fax: FaxNumber) -> Self {
+        Self::Fax(fax)
+    }
+}
+
+/// What is printed or faxed.
+pub enum Content {
+    Upload(NonEmptyVec<Document>),
+    Text(Text),
+    Template(Template),
+
+    /// A document the server fetches itself, must be an `http` or `https` URL (code 78)
+    Url(String),
+}
+
+impl Content {
+    /// A document the server fetches from `url` instead of it being uploaded.
+    pub fn url(url: impl Into<String>) -> Self {
+        Self::Url(url.into())
+    }
+
+    fn content_type(&self) -> &'static str {
+        match self {
+            Self::Upload(_) => "upload",
+            Self::Text(_) => "text",
+            Self::Template(_) => "template",
+            Self::Url(_) => "url",
         }
     }
 }
 
+impl From<Document> for Content {
+    fn from(document: Document) -> Self {
+        Self::Upload(NonEmptyVec::new(document))
+    }
+}
+
+impl From<NonEmptyVec<Document>> for Content {
+    fn from(documents: NonEmptyVec<Document>) -> Self {
+        Self::Upload(documents)
+    }
+}
+
+impl From<reqwest::Url> for Content {
+    fn from(url: reqwest::Url) -> Self {
+        Self::Url(url.into())
+    }
+}
+
+impl From<Text> for Content {
+    fn from(text: Text) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Template> for Content {
+    fn from(template: Template) -> Self {
+        Self::Template(template)
+    }
+}
+
 #[derive(Builder)]
 pub struct Letter {
     destination: CountryCode,
     location: Option<Location>,
     services: Option<Vec<Addoption>>,
+    cash_on_delivery: Option<CashOnDelivery>,
+
+    /// Let Deutsche Post report the recipient's new address if they moved
+    premiumadress: Option<bool>,
+}
+
+/// The sender line printed on a letter, accounts can store several of them in the customer area
+#[derive(Debug, Clone, PartialEq)]
+pub enum SenderProfile {
+    /// Number of a sender line stored in the customer area
+    Stored(u32),
+
+    /// A free-text return address
+    Inline(String),
+}
+
+impl SenderProfile {
+    // Code 63, stored sender lines are numbered from 1
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::Stored(0) => Err(Error::Rejected(PixelletterErrorCode::Code63)),
+            _ => Ok(()),
+        }
+    }
 }
 
 #[derive(Builder)]
 pub struct Text {
-    address: String,
+    /// Either an [`Address`] or the lines of the address joined with `\n`
+    #[builder(into)]
+    address: AddressBlock,
     message: String,
     font: String,
-    return_address: String,
+    return_address: Option<String>,
+}
+
+#[derive(Builder)]
+pub struct Template {
+    number: u32,
+    fields: Option<BTreeMap<String, String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    // Answers a single request with `body` and returns the base URL to send it to
+    async fn serve(body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 4096];
+            loop {
+                let n = stream.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+
+                let text = String::from_utf8_lossy(&request);
+                let Some(header_end) = text.find("\r\n\r\n") else {
+                    continue;
+                };
+                let content_length = text[..header_end]
+                    .lines()
+                    .find_map(|line| {
+                        let (name, value) = line.split_once(':')?;
+                        name.eq_ignore_ascii_case("content-length")
+                            .then(|| value.trim().parse::<usize>().unwrap())
+                    })
+                    .unwrap_or(0);
+                if request.len() >= header_end + 4 + content_length {
+                    break;
+                }
+            }
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        format!("http://{addr}/")
+    }
+
+    fn client(base_url: String) -> Client {
+        Client::builder()
+            .base_url(base_url)
+            .email("manfred@mueller.de".to_owned())
+            .password("sa8Lioi".to_owned())
+            .agb(true)
+            .widerrufsverzicht(true)
+            .build()
+    }
+
+    async fn cancel(body: &'static str) -> Result<CancelOutcome> {
+        client(serve(body).await)
+            .cancel_order("4711".to_owned())
+            .await
+    }
+
+    async fn submit(body: &'static str) -> Result<OrderReceipt> {
+        let letter = Letter::builder().destination(CountryCode::DEU).build();
+
+        client(serve(body).await)
+            .order()
+            .kind(letter)
+            .content(Document::pdf("letter.pdf".to_owned(), b"%PDF-1.7".to_vec()))
+            .transaction("1234567890".to_owned())
+            .submit()
+            .await
+    }
+
+    #[tokio::test]
+    async fn order_transaction_echoed() {
+        let receipt = submit(
+            r#"<pixelletter version="1.3"><response><result code="100"><msg>OK</msg></result><transaction>1234567890</transaction><id>4711</id></response></pixelletter>"#,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(receipt.order_id.as_deref(), Some("4711"));
+        assert!(!receipt.transaction_mismatch());
+    }
+
+    #[tokio::test]
+    async fn order_transaction_mismatch_is_accepted() {
+        let receipt = submit(
+            r#"<pixelletter version="1.3"><response><result code="100"><msg>OK</msg></result><transaction>0987654321</transaction></response></pixelletter>"#,
+        )
+        .await
+        .unwrap();
+
+        assert!(receipt.transaction_mismatch());
+    }
+
+    #[tokio::test]
+    async fn order_transaction_not_echoed() {
+        let receipt = submit(
+            r#"<pixelletter version="1.3"><response><result code="100"><msg>OK</msg></result></response></pixelletter>"#,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(receipt.transaction, None);
+        assert!(!receipt.transaction_mismatch());
+    }
+
+    #[tokio::test]
+    async fn order_validates_structured_address() {
+        let address = Address::builder()
+            .name("Manfred Müller".to_owned())
+            .street("Hauptstraße 1".to_owned())
+            .postcode("1011".to_owned())
+            .city("Berlin".to_owned())
+            .country(CountryCode::DEU)
+            .build();
+        let text = Text::builder()
+            .address(address)
+            .message("Vielen Dank!".to_owned())
+            .font("Arial".to_owned())
+            .build();
+
+        // Nothing listens on the discard port, the order has to fail before it is sent
+        let outcome = client("http://127.0.0.1:9/".to_owned())
+            .order()
+            .kind(Letter::builder().destination(CountryCode::DEU).build())
+            .content(text)
+            .submit()
+            .await;
+
+        assert!(matches!(
+            outcome,
+            Err(Error::Rejected(PixelletterErrorCode::Code207))
+        ));
+    }
+
+    #[tokio::test]
+    async fn order_rejects_address_abroad() {
+        let address = Address::builder()
+            .name("Erika Muster".to_owned())
+            .street("Ringstraße 12".to_owned())
+            .postcode("1010".to_owned())
+            .city("Wien".to_owned())
+            .country(CountryCode::AUT)
+            .build();
+        let text = Text::builder()
+            .address(address)
+            .message("Vielen Dank!".to_owned())
+            .font("Arial".to_owned())
+            .build();
+
+        let outcome = client("http://127.0.0.1:9/".to_owned())
+            .order()
+            .kind(Letter::builder().destination(CountryCode::DEU).build())
+            .content(text)
+            .submit()
+            .await;
+
+        assert!(matches!(outcome, Err(Error::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn order_rejects_unfetchable_urls() {
+        let url = reqwest::Url::parse("ftp://example.com/letter.pdf").unwrap();
+
+        for content in [Content::url("example.com/letter.pdf"), Content::from(url)] {
+            let outcome = client("http://127.0.0.1:9/".to_owned())
+                .order()
+                .kind(Letter::builder().destination(CountryCode::DEU).build())
+                .content(content)
+                .submit()
+                .await;
+
+            assert!(matches!(
+                outcome,
+                Err(Error::Rejected(PixelletterErrorCode::Code78))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn bulk_order_recipient_limit() {
+        let recipient = BulkRecipient::builder()
+            .reference("K-1".to_owned())
+            .fields(BTreeMap::new())
+            .build();
+        let order = BulkOrder::builder()
+            .template(1)
+            .destination(CountryCode::DEU)
+            .recipients(vec![recipient; BULK_MAX_RECIPIENTS + 1])
+            .build();
+
+        let outcome = client("http://127.0.0.1:9/".to_owned())
+            .bulk_order(order)
+            .await;
+
+        assert!(matches!(outcome, Err(Error::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn credit_guard_rejects_other_currencies() {
+        let guard = CreditGuard::builder()
+            .minimum(Money::new(500, "USD".parse().unwrap()))
+            .build();
+        let client = Client::builder()
+            .base_url("http://127.0.0.1:9/".to_owned())
+            .email("manfred@mueller.de".to_owned())
+            .password("sa8Lioi".to_owned())
+            .agb(true)
+            .widerrufsverzicht(true)
+            .credit_guard(guard)
+            .build();
+        *client.credit_cache.lock().unwrap() = Some((Instant::now(), Money::eur(100_000)));
+
+        assert!(matches!(
+            client.ensure_credit(None).await,
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn cancel_order_cancelled() {
+        let outcome = cancel(
+            r#"<pixelletter version="1.3"><response><result code="100"><msg>OK</msg></result><status>storniert</status></response></pixelletter>"#,
+        )
+        .await;
+
+        assert_eq!(outcome.unwrap(), CancelOutcome::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn cancel_order_too_late() {
+        let outcome = cancel(
+            r#"<pixelletter version="1.3"><response><result code="100"><msg>OK</msg></result><status>printed</status></response></pixelletter>"#,
+        )
+        .await;
+
+        assert_eq!(
+            outcome.unwrap(),
+            CancelOutcome::TooLate(OrderStatus::Printed)
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel_order_not_found() {
+        let outcome = cancel(
+            r#"<pixelletter version="1.3"><response><result code="55"><msg>Not found</msg></result></response></pixelletter>"#,
+        )
+        .await;
+
+        assert_eq!(outcome.unwrap(), CancelOutcome::NotFound);
+    }
+
+    #[tokio::test]
+    async fn cancel_order_without_status() {
+        let outcome = cancel(
+            r#"<pixelletter version="1.3"><response><result code="100"><msg>OK</msg></result></response></pixelletter>"#,
+        )
+        .await;
+
+        assert!(matches!(outcome, Err(Error::MissingField("status"))));
+    }
 }
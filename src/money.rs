@@ -0,0 +1,168 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseMoneyError {
+    #[error("Invalid amount, expected the format `1.234,56`, but got: {0}")]
+    Amount(String),
+
+    #[error("Invalid currency, expected an ISO 4217 code like `EUR`, but got: {0}")]
+    Currency(String),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency([u8; 3]);
+
+impl Currency {
+    pub const EUR: Self = Self(*b"EUR");
+
+    pub fn as_str(&self) -> &str {
+        // Only ever constructed from ASCII uppercase letters
+        std::str::from_utf8(&self.0).unwrap()
+    }
+}
+
+impl Default for Currency {
+    fn default() -> Self {
+        Self::EUR
+    }
+}
+
+impl FromStr for Currency {
+    type Err = ParseMoneyError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let code = value.trim().to_ascii_uppercase();
+
+        match <[u8; 3]>::try_from(code.as_bytes()) {
+            Ok(bytes) if bytes.iter().all(u8::is_ascii_uppercase) => Ok(Self(bytes)),
+            _ => Err(ParseMoneyError::Currency(value.to_owned())),
+        }
+    }
+}
+
+impl fmt::Debug for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Currency").field(&self.as_str()).finish()
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An exact amount of money in the smallest unit (cents) of its currency.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    cents: i64,
+    currency: Currency,
+}
+
+impl Money {
+    pub fn new(cents: i64, currency: Currency) -> Self {
+        Self { cents, currency }
+    }
+
+    pub fn eur(cents: i64) -> Self {
+        Self::new(cents, Currency::EUR)
+    }
+
+    /// Parses the German decimal format used by the API, e.g. `1.234,56` or `1234,56`.
+    pub fn parse_german(amount: &str, currency: Currency) -> Result<Self, ParseMoneyError> {
+        let invalid = || ParseMoneyError::Amount(amount.to_owned());
+
+        let trimmed = amount.trim();
+        let (negative, unsigned) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+
+        let (integer, fraction) = unsigned.split_once(',').unwrap_or((unsigned, ""));
+
+        // Thousands separators are optional, but if present they have to group by three
+        let groups: Vec<&str> = integer.split('.').collect();
+        let grouped_correctly = groups.len() == 1
+            || (!groups[0].is_empty()
+                && groups[0].len() <= 3
+                && groups[1..].iter().all(|group| group.len() == 3));
+
+        let digits: String = groups.concat();
+        if digits.is_empty()
+            || !grouped_correctly
+            || fraction.len() > 2
+            || !digits
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let units: i64 = digits.parse().map_err(|_| invalid())?;
+        let cents: i64 = format!("{fraction:0<2}").parse().map_err(|_| invalid())?;
+
+        let total = units
+            .checked_mul(100)
+            .and_then(|value| value.checked_add(cents))
+            .ok_or_else(invalid)?;
+
+        Ok(Self::new(if negative { -total } else { total }, currency))
+    }
+
+    pub fn cents(&self) -> i64 {
+        self.cents
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Formats the amount the way the API expects it, without thousands separators, e.g. `1234,56`.
+    pub fn to_german_string(&self) -> String {
+        let sign = if self.cents < 0 { "-" } else { "" };
+        let abs = self.cents.unsigned_abs();
+
+        format!("{sign}{},{:02}", abs / 100, abs % 100)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        (self.currency == other.currency)
+            .then(|| self.cents.checked_add(other.cents))
+            .flatten()
+            .map(|cents| Self::new(cents, self.currency))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        (self.currency == other.currency)
+            .then(|| self.cents.checked_sub(other.cents))
+            .flatten()
+            .map(|cents| Self::new(cents, self.currency))
+    }
+}
+
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.currency == other.currency).then(|| self.cents.cmp(&other.cents))
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.cents < 0 { "-" } else { "" };
+        let abs = self.cents.unsigned_abs();
+
+        let units = (abs / 100).to_string();
+        let mut grouped = String::with_capacity(units.len() + units.len() / 3);
+        for (index, digit) in units.chars().enumerate() {
+            if index > 0 && (units.len() - index).is_multiple_of(3) {
+                grouped.push('.');
+            }
+            grouped.push(digit);
+        }
+
+        write!(f, "{sign}{grouped},{:02} {}", abs % 100, self.currency)
+    }
+}
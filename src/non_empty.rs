@@ -0,0 +1,66 @@
+use std::ops::Deref;
+
+use crate::{Error, Result};
+
+/// A `Vec` that holds at least one element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonEmptyVec<T>(Vec<T>);
+
+impl<T> NonEmptyVec<T> {
+    pub fn new(first: T) -> Self {
+        Self(vec![first])
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.0.push(value);
+    }
+
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for NonEmptyVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = Error;
+
+    fn try_from(values: Vec<T>) -> Result<Self> {
+        if values.is_empty() {
+            return Err(Error::Validation("The list is empty!".to_owned()));
+        }
+
+        Ok(Self(values))
+    }
+}
+
+impl<T> From<T> for NonEmptyVec<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    fn from(values: NonEmptyVec<T>) -> Self {
+        values.0
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
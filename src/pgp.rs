@@ -0,0 +1,121 @@
+use std::path::Path;
+
+use ::pgp::{
+    crypto::sym::SymmetricKeyAlgorithm, ser::Serialize, types::PublicKeyTrait, Deserializable,
+    Message, SignedPublicKey,
+};
+
+use crate::{Document, Result};
+
+const ARMOR_HEADER: &[u8] = b"-----BEGIN";
+const ENCRYPTED_MIME: &str = "application/pgp-encrypted";
+
+/// The public key every uploaded document is encrypted to, usually Pixelletter's key.
+#[derive(Debug, Clone)]
+pub struct PgpKey(SignedPublicKey);
+
+impl PgpKey {
+    /// Reads an ASCII-armored or binary public key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let key = if bytes.trim_ascii_start().starts_with(ARMOR_HEADER) {
+            SignedPublicKey::from_armor_single(bytes)?.0
+        } else {
+            SignedPublicKey::from_bytes(bytes)?
+        };
+        key.verify()?;
+
+        Ok(Self(key))
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    pub(crate) fn encrypt(&self, document: Document) -> Result<Document> {
+        let mut rng = rand::thread_rng();
+        let algorithm = SymmetricKeyAlgorithm::AES256;
+        let message = Message::new_literal_bytes(document.file_name(), document.bytes());
+
+        // Prefer a dedicated encryption subkey, primary keys are often only allowed to sign
+        let encrypted = match self
+            .0
+            .public_subkeys
+            .iter()
+            .find(|subkey| subkey.key.is_encryption_key())
+        {
+            Some(subkey) => message.encrypt_to_keys_seipdv1(&mut rng, algorithm, &[&subkey.key])?,
+            None => message.encrypt_to_keys_seipdv1(&mut rng, algorithm, &[&self.0.primary_key])?,
+        };
+
+        Ok(Document::builder()
+            .file_name(format!("{}.pgp", document.file_name()))
+            .bytes(encrypted.to_bytes()?)
+            .mime(ENCRYPTED_MIME.to_owned())
+            .build())
+    }
+}
+
+#[cfg(all(test, feature = "pgp"))]
+mod tests {
+    use ::pgp::{
+        types::SecretKeyTrait, ArmorOptions, KeyType, SecretKeyParamsBuilder, SignedSecretKey,
+        SubkeyParamsBuilder,
+    };
+
+    use super::*;
+
+    fn generate_key() -> (SignedSecretKey, SignedPublicKey) {
+        let mut rng = rand::thread_rng();
+        let params = SecretKeyParamsBuilder::default()
+            .key_type(KeyType::Rsa(2048))
+            .can_certify(true)
+            .can_sign(true)
+            .primary_user_id("Manfred Müller <manfred@mueller.de>".to_owned())
+            .subkey(
+                SubkeyParamsBuilder::default()
+                    .key_type(KeyType::Rsa(2048))
+                    .can_encrypt(true)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let secret_key = params
+            .generate(&mut rng)
+            .unwrap()
+            .sign(&mut rng, String::new)
+            .unwrap();
+        let public_key = secret_key
+            .public_key()
+            .sign(&mut rng, &secret_key, String::new)
+            .unwrap();
+
+        (secret_key, public_key)
+    }
+
+    #[test]
+    fn encrypts_to_armored_and_binary_keys() {
+        let (secret_key, public_key) = generate_key();
+        let armored = public_key
+            .to_armored_bytes(ArmorOptions::default())
+            .unwrap();
+        let binary = public_key.to_bytes().unwrap();
+
+        for key in [&armored, &binary] {
+            let key = PgpKey::from_bytes(key).unwrap();
+            let document = Document::pdf("letter.pdf".to_owned(), b"%PDF-1.7".to_vec());
+
+            let encrypted = key.encrypt(document).unwrap();
+            assert_eq!(encrypted.file_name(), "letter.pdf.pgp");
+            assert_eq!(encrypted.mime(), ENCRYPTED_MIME);
+            assert_ne!(encrypted.bytes(), b"%PDF-1.7");
+
+            let (message, _) = Message::from_bytes(encrypted.bytes())
+                .unwrap()
+                .decrypt(String::new, &[&secret_key])
+                .unwrap();
+            assert_eq!(message.get_content().unwrap().unwrap(), b"%PDF-1.7");
+        }
+    }
+}
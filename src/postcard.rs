@@ -0,0 +1,180 @@
+use bon::Builder;
+use isocountry::CountryCode;
+
+use crate::{
+    api::{self, ActionType, Command, Location, Options, Order, PixelletterErrorCode},
+    AddressBlock, Client, Document, Error, Money, OrderReceipt, Result, API_VERSION,
+};
+
+// Limits from the messages of codes 75 and 73
+pub const POSTCARD_MAX_PHOTO_BYTES: usize = 6 * 1024 * 1024;
+pub const POSTCARD_MAX_ADDRESS_LINES: usize = 6;
+
+const JPEG_MAGIC: [u8; 3] = [0xFF, 0xD8, 0xFF];
+
+#[derive(Builder)]
+pub struct Postcard {
+    /// The JPEG photo printed on the front
+    photo: Vec<u8>,
+
+    /// Either an [`Address`](crate::Address) or the lines of the address joined with `\n`
+    #[builder(into)]
+    address: AddressBlock,
+    message: String,
+
+    /// The server rejects texts that are too long (code 74) without documenting the limit, set
+    /// this to check a known limit locally
+    max_message_chars: Option<usize>,
+
+    destination: CountryCode,
+    transaction: Option<String>,
+    estimated_cost: Option<Money>,
+}
+
+impl Postcard {
+    // Codes 72 to 76
+    pub fn validate(&self) -> Result<()> {
+        self.address.validate()?;
+        self.address.check_destination(self.destination)?;
+
+        let code = if self.photo.is_empty() {
+            PixelletterErrorCode::Code76
+        } else if !self.photo.starts_with(&JPEG_MAGIC) {
+            PixelletterErrorCode::Code72
+        } else if self.photo.len() > POSTCARD_MAX_PHOTO_BYTES {
+            PixelletterErrorCode::Code75
+        } else if self.address.to_string().lines().count() > POSTCARD_MAX_ADDRESS_LINES {
+            PixelletterErrorCode::Code73
+        } else if self
+            .max_message_chars
+            .is_some_and(|max| self.message.chars().count() > max)
+        {
+            PixelletterErrorCode::Code74
+        } else {
+            return Ok(());
+        };
+
+        Err(Error::Rejected(code))
+    }
+}
+
+impl Client {
+    pub async fn postcard(&self, postcard: Postcard) -> Result<OrderReceipt> {
+        postcard.validate()?;
+
+        let request = api::Pixelletter {
+            version: API_VERSION.to_owned(),
+            auth: Some(self.auth.clone()),
+            command: Some(Command {
+                order: Some(Order {
+                    content_type: "postcard".to_owned(),
+
+                    options: Options {
+                        action: Some(ActionType::Letter),
+                        transaction: postcard.transaction,
+                        destination: Some(postcard.destination.alpha2().to_owned()),
+                        ..Default::default()
+                    },
+
+                    text: Some(api::Text {
+                        address: postcard.address.into_lines(Location::default().country()),
+                        message: postcard.message,
+                    }),
+
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let photo = Document::builder()
+            .file_name("photo.jpg".to_owned())
+            .bytes(postcard.photo)
+            .mime("image/jpeg".to_owned())
+            .build();
+
+        self.submit_order(&request, vec![photo], postcard.estimated_cost)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn postcard(photo: Vec<u8>, address: &str, message: &str) -> Postcard {
+        Postcard::builder()
+            .photo(photo)
+            .address(address.to_owned())
+            .message(message.to_owned())
+            .destination(CountryCode::DEU)
+            .build()
+    }
+
+    fn jpeg(len: usize) -> Vec<u8> {
+        let mut photo = vec![0; len];
+        photo[..JPEG_MAGIC.len()].copy_from_slice(&JPEG_MAGIC);
+        photo
+    }
+
+    #[test]
+    fn photo_must_be_jpeg() {
+        assert!(postcard(jpeg(16), "Erika Muster", "Danke!")
+            .validate()
+            .is_ok());
+        assert!(matches!(
+            postcard(b"\x89PNG\r\n\x1a\n".to_vec(), "Erika Muster", "Danke!").validate(),
+            Err(Error::Rejected(PixelletterErrorCode::Code72))
+        ));
+        assert!(matches!(
+            postcard(Vec::new(), "Erika Muster", "Danke!").validate(),
+            Err(Error::Rejected(PixelletterErrorCode::Code76))
+        ));
+    }
+
+    #[test]
+    fn photo_size_limit() {
+        assert!(
+            postcard(jpeg(POSTCARD_MAX_PHOTO_BYTES), "Erika Muster", "Danke!")
+                .validate()
+                .is_ok()
+        );
+        assert!(matches!(
+            postcard(jpeg(POSTCARD_MAX_PHOTO_BYTES + 1), "Erika Muster", "Danke!").validate(),
+            Err(Error::Rejected(PixelletterErrorCode::Code75))
+        ));
+    }
+
+    #[test]
+    fn address_line_limit() {
+        let address = ["Zeile"; POSTCARD_MAX_ADDRESS_LINES].join("\n");
+        assert!(postcard(jpeg(16), &address, "Danke!").validate().is_ok());
+
+        let address = ["Zeile"; POSTCARD_MAX_ADDRESS_LINES + 1].join("\n");
+        assert!(matches!(
+            postcard(jpeg(16), &address, "Danke!").validate(),
+            Err(Error::Rejected(PixelletterErrorCode::Code73))
+        ));
+    }
+
+    #[test]
+    fn message_limit_is_optional() {
+        let message = "a".repeat(10_000);
+        assert!(postcard(jpeg(16), "Erika Muster", &message)
+            .validate()
+            .is_ok());
+
+        let postcard = Postcard::builder()
+            .photo(jpeg(16))
+            .address("Erika Muster".to_owned())
+            .message("Vielen Dank!".to_owned())
+            .max_message_chars(6)
+            .destination(CountryCode::DEU)
+            .build();
+        assert!(matches!(
+            postcard.validate(),
+            Err(Error::Rejected(PixelletterErrorCode::Code74))
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+use bon::Builder;
+
+use crate::{
+    api::{self, Command, Options, Order, PixelletterErrorCode},
+    Client, Document, Error, OrderReceipt, Result, API_VERSION,
+};
+
+const MAX_FIELD_CHARS: usize = 255;
+
+/// A document sent out for electronic signature, the notification e-mail is sent from
+/// `sender_email` to `recipient_email`.
+#[derive(Builder)]
+pub struct SignatureOrder {
+    file: Document,
+    sender_email: String,
+    recipient_email: String,
+    subject: String,
+    message: String,
+    transaction: Option<String>,
+}
+
+impl SignatureOrder {
+    // Codes 39 to 45
+    pub fn validate(&self) -> Result<()> {
+        let too_long = |value: &str| value.chars().count() > MAX_FIELD_CHARS;
+
+        let code = if self.sender_email.is_empty() {
+            PixelletterErrorCode::Code39
+        } else if too_long(&self.sender_email) {
+            PixelletterErrorCode::Code40
+        } else if self.recipient_email.is_empty() {
+            PixelletterErrorCode::Code41
+        } else if too_long(&self.recipient_email) {
+            PixelletterErrorCode::Code42
+        } else if self.subject.is_empty() {
+            PixelletterErrorCode::Code43
+        } else if too_long(&self.subject) {
+            PixelletterErrorCode::Code44
+        } else if self.message.is_empty() {
+            PixelletterErrorCode::Code45
+        } else {
+            return Ok(());
+        };
+
+        Err(Error::Rejected(code))
+    }
+}
+
+impl Client {
+    /// Submits a document for electronic signature.
+    ///
+    /// Fails with [`Error::SignatureNotActivated`] if the service has not been activated in the
+    /// customer area yet.
+    pub async fn sign(&self, order: SignatureOrder) -> Result<OrderReceipt> {
+        order.validate()?;
+
+        let request = api::Pixelletter {
+            version: API_VERSION.to_owned(),
+            auth: Some(self.auth.clone()),
+            command: Some(Command {
+                order: Some(Order {
+                    content_type: "signature".to_owned(),
+
+                    options: Options {
+                        transaction: order.transaction,
+                        ..Default::default()
+                    },
+
+                    signature: Some(api::Signature {
+                        sender: order.sender_email,
+                        recipient: order.recipient_email,
+                        subject: order.subject,
+                        message: order.message,
+                    }),
+
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        self.submit_order(&request, vec![order.file], None)
+            .await
+            .map_err(|err| match err {
+                Error::Api { code: 46, .. } => Error::SignatureNotActivated,
+                other => other,
+            })
+    }
+}